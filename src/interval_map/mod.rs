@@ -1,6 +1,7 @@
+use std::borrow::Borrow;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
-use std::ops::Index;
+use std::ops::{Bound, Index, Range};
 use std::hash::Hash;
 
 mod test;
@@ -73,16 +74,201 @@ where
         true
     }
 
+    /// Iterate over the maximal half-open intervals that hold a non-default
+    /// value, yielded in ascending key order as `(key_begin..key_end, value)`.
+    ///
+    /// The region before the first stored key and the region after the last
+    /// stored key are always the default value, so they're never yielded
+    /// here; use [`iter_with_defaults`](Self::iter_with_defaults) to also see
+    /// the default-valued runs *between* stored intervals.
+    pub fn iter(&self) -> impl Iterator<Item = (Range<K>, &V)> + '_ {
+        self.windows().filter(move |(_, value)| value != &&self.default_value)
+    }
+
+    /// Iterate over every maximal half-open interval between stored keys,
+    /// in ascending key order, including the default-valued runs that fall
+    /// between assigned intervals.
+    ///
+    /// Like [`iter`](Self::iter), this never yields the unbounded region
+    /// before the first stored key or after the last one: `K` is only
+    /// bounded by `Ord`, with no general notion of a minimum or maximum
+    /// value to anchor such a range on, so those two runs can only be
+    /// queried pointwise through indexing, not enumerated as a `Range<K>`.
+    pub fn iter_with_defaults(&self) -> impl Iterator<Item = (Range<K>, &V)> + '_ {
+        self.windows()
+    }
+
+    /// Pair up consecutive stored keys into their half-open intervals.
+    fn windows(&self) -> impl Iterator<Item = (Range<K>, &V)> + '_ {
+        self.value_map.keys().zip(self.value_map.keys().skip(1))
+            .map(move |(begin, end)| (begin.clone()..end.clone(), self.value_map.get(begin).unwrap()))
+    }
+
+    /// Returns whether any non-default interval intersects `[begin, end)`.
+    ///
+    /// Default-valued regions don't count, so this answers "is anything
+    /// assigned here" rather than "is this key range in bounds".
+    pub fn overlaps(&self, begin: &K, end: &K) -> bool {
+        self.iter().any(|(range, _)| range.start < *end && *begin < range.end)
+    }
+
+    /// Returns the start, end, and value of the maximal non-default interval
+    /// containing `point`, or `None` if `point` falls in a default-valued
+    /// region.
+    pub fn covering_interval(&self, point: &K) -> Option<(K, K, &V)> {
+        let (start, value) = match self.value_map.get_key_value(point) {
+            Some((key, value)) => (key, value),
+            None => {
+                let key = self.previous_key(point)?;
+                (key, self.value_map.get(key).unwrap())
+            }
+        };
+        if value == &self.default_value {
+            return None;
+        }
+        let end = self.next_key(start).unwrap().clone();
+        Some((start.clone(), end, value))
+    }
+
+    /// Iterate over every non-default interval that intersects `[begin, end)`,
+    /// clipped to that window, in ascending key order.
+    pub fn intervals_in(&self, begin: &K, end: &K) -> impl Iterator<Item = (Range<K>, &V)> + '_ {
+        let begin = begin.clone();
+        let end = end.clone();
+        self.iter().filter_map(move |(range, value)| {
+            if range.end <= begin || range.start >= end {
+                return None;
+            }
+            let clipped_start = if range.start < begin { begin.clone() } else { range.start };
+            let clipped_end = if range.end > end { end.clone() } else { range.end };
+            Some((clipped_start..clipped_end, value))
+        })
+    }
+
+    /// Merge `other` into `self` by combining the two maps pointwise: at
+    /// every point the result value becomes `combine(self[point],
+    /// other[point])`.
+    ///
+    /// Both maps' boundary keys are walked in sorted order simultaneously,
+    /// so the result stays canonical (no two adjacent stored intervals share
+    /// a value) without a separate cleanup pass.
+    pub fn merge_with<F: Fn(&V, &V) -> V>(&mut self, other: &IntervalMap<K, V>, combine: F) {
+        let new_default = combine(&self.default_value, &other.default_value);
+
+        let mut self_entries = self.value_map.iter().peekable();
+        let mut other_entries = other.value_map.iter().peekable();
+        let mut merged: BTreeMap<K, V> = BTreeMap::new();
+        let mut running_value: Option<V> = None;
+        // The value each side holds at the current sweep position, carried
+        // forward from the last boundary key we crossed rather than looked
+        // up again, so no step searches either input map.
+        let mut self_value = self.default_value.clone();
+        let mut other_value = other.default_value.clone();
+
+        loop {
+            let next_key = match (self_entries.peek(), other_entries.peek()) {
+                (Some(&(a, _)), Some(&(b, _))) => a.min(b).clone(),
+                (Some(&(a, _)), None) => a.clone(),
+                (None, Some(&(b, _))) => b.clone(),
+                (None, None) => break,
+            };
+            if self_entries.peek().map(|&(k, _)| k) == Some(&next_key) {
+                let (_, value) = self_entries.next().unwrap();
+                self_value = value.clone();
+            }
+            if other_entries.peek().map(|&(k, _)| k) == Some(&next_key) {
+                let (_, value) = other_entries.next().unwrap();
+                other_value = value.clone();
+            }
+
+            let value = combine(&self_value, &other_value);
+            let starts_new_segment = match &running_value {
+                Some(prev) => prev != &value,
+                None => value != new_default,
+            };
+            if starts_new_segment {
+                merged.insert(next_key, value.clone());
+            }
+            running_value = Some(value);
+        }
+
+        self.default_value = new_default;
+        self.value_map = merged;
+    }
+
+    /// Iterate over the sub-ranges of `[begin, end)` that hold the default
+    /// value — the "holes" between assigned intervals — in ascending order.
+    ///
+    /// This is the complement of [`intervals_in`](Self::intervals_in) over
+    /// the same window, and is handy for allocation-style lookups where
+    /// assigned intervals represent occupied regions.
+    pub fn gaps(&self, begin: &K, end: &K) -> impl Iterator<Item = Range<K>> + '_ {
+        let mut segments: Vec<(Range<K>, V)> = Vec::new();
+
+        if begin < end {
+            let mut cursor = begin.clone();
+            let mut cursor_value = self[begin].clone();
+
+            for (key, value) in self.value_map.range((Bound::Excluded(begin), Bound::Excluded(end))) {
+                segments.push((cursor.clone()..key.clone(), cursor_value));
+                cursor = key.clone();
+                cursor_value = value.clone();
+            }
+            segments.push((cursor..end.clone(), cursor_value));
+        }
+
+        segments.into_iter()
+            .filter(move |(_, value)| value == &self.default_value)
+            .map(|(range, _)| range)
+    }
+
+    /// Look up the value at `point`, like indexing, but usable with a
+    /// borrowed form of `K` (e.g. `&str` for `IntervalMap<String, _>`),
+    /// mirroring `BTreeMap::get`'s `Borrow`-based ergonomics.
+    ///
+    /// Since `IntervalMap` always resolves to a value outside assigned
+    /// ranges, this returns `&V` directly rather than `Option<&V>`; see
+    /// [`get_exact`](Self::get_exact) for a fallible, boundary-only lookup.
+    pub fn get<Q>(&self, point: &Q) -> &V
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self.value_map.get(point) {
+            Some(value) => value,
+            None => match self.value_map.range((Bound::Unbounded, Bound::Excluded(point))).next_back() {
+                Some((_, value)) => value,
+                None => &self.default_value,
+            },
+        }
+    }
+
+    /// Look up the value at `point` only if it's exactly a stored boundary
+    /// key, returning `None` for interior points. Boundary keys include the
+    /// key where a default-valued run begins, such as the end of an assigned
+    /// interval, which returns `Some(default)`.
+    ///
+    /// Interior points still resolve to a value through [`get`](Self::get);
+    /// this is for callers that need to distinguish a boundary key from a
+    /// point that merely falls within one.
+    pub fn get_exact<Q>(&self, point: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.value_map.get(point)
+    }
+
     /// Get the maximum value key (i.e. the last key). Returns None
     /// if the interval map is empty.
     pub fn max_key(&self) -> Option<&K> {
-        self.value_map.keys().max()
+        self.value_map.keys().next_back()
     }
 
     /// Get the maximum value key (i.e. the last key). Returns None
     /// if the interval map is empty.
     pub fn min_key(&self) -> Option<&K> {
-        self.value_map.keys().min()
+        self.value_map.keys().next()
     }
 
     /// If the key domain exceeds a threshold (i.e. the next element is past
@@ -99,21 +285,20 @@ where
 
     /// Get all the keys in range in IntervalMap
     fn keys_in_range(&self, start: &K, end: &K) -> Vec<K> {
-        self.value_map.keys()
-            .filter(|&key| key >= start && key < end)
-            .cloned().collect::<Vec<K>>()
+        self.value_map.range((Bound::Included(start), Bound::Excluded(end)))
+            .map(|(key, _)| key.clone()).collect::<Vec<K>>()
     }
 
     /// For any given key, return the next key. Returns None
     /// if there is no next key.
     fn next_key(&self, key: &K) -> Option<&K> {
-        self.value_map.keys().filter(|&k| k > key).min()
+        self.value_map.range((Bound::Excluded(key), Bound::Unbounded)).next().map(|(k, _)| k)
     }
 
-    /// For any given key, get the previous key in IntervalMap. Returns None 
+    /// For any given key, get the previous key in IntervalMap. Returns None
     /// if there is no previous key.
     fn previous_key(&self, key: &K) -> Option<&K> {
-        self.value_map.keys().filter(|&k| k < key).max()
+        self.value_map.range((Bound::Unbounded, Bound::Excluded(key))).next_back().map(|(k, _)| k)
     }
 
     /// For any given key, return the next key in IntervalMap's value.
@@ -133,7 +318,7 @@ where
     }
 }
 
-impl<K, V> Index<&K> for IntervalMap<K, V> 
+impl<K, V> Index<&K> for IntervalMap<K, V>
 where
     K: Ord + Eq + Hash + Clone,
     V: Eq + Clone,
@@ -144,7 +329,7 @@ where
         let min_key = self.min_key();
         if min_key.is_none() || Some(key) < min_key { // get default
             return &self.default_value;
-        } 
+        }
         match self.value_map.get(key) {
             Some(val) => { val },
             None => { self.previous_value(key) },
@@ -152,7 +337,20 @@ where
     }
 }
 
-impl<K, V> Debug for IntervalMap<K, V> 
+impl<'a, K, V> IntoIterator for &'a IntervalMap<K, V>
+where
+    K: Ord + Eq + Hash + Clone,
+    V: Eq + Clone,
+{
+    type Item = (Range<K>, &'a V);
+    type IntoIter = Box<dyn Iterator<Item = (Range<K>, &'a V)> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+impl<K, V> Debug for IntervalMap<K, V>
 where
     K: Ord + Eq + Hash + Debug + Clone,
     V: Eq + Debug + Clone,
@@ -160,4 +358,120 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_map().entries(self.value_map.iter().map(|(k, v)| (k, v))).finish()
     }
+}
+
+/// Key types that form a discrete, successor-defined sequence, letting
+/// [`InclusiveIntervalMap`] tell when two inclusive ranges are touching
+/// (e.g. `[0, 5]` and `[6, 10]`) and should collapse into one segment.
+///
+/// Only implemented for discrete types; continuous types like `f64` have no
+/// well-defined "next" value, so they stay on the half-open `IntervalMap`.
+pub trait Successor: Sized {
+    /// Returns the value immediately after `self` in the discrete sequence,
+    /// or `None` if `self` is already the maximum representable value.
+    fn successor(&self) -> Option<Self>;
+}
+
+macro_rules! impl_successor {
+    ($($int:ty),+) => {
+        $(impl Successor for $int {
+            fn successor(&self) -> Option<Self> { self.checked_add(1) }
+        })+
+    };
+}
+impl_successor!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// An `IntervalMap` variant with inclusive endpoints, so a single point can
+/// be assigned as `[k, k]` and adjacent ranges like `[0, 5]` and `[6, 10]`
+/// can be expressed without knowing the successor of `5` yourself.
+///
+/// Wraps a half-open `IntervalMap`: `assign_inclusive` converts `key_end` to
+/// the half-open bound `key_end.successor()`, absorbing a touching
+/// same-valued run first so the result stays canonical.
+pub struct InclusiveIntervalMap<K, V>
+where
+    K: Ord + Eq + Hash + Clone + Successor,
+    V: Eq + Clone,
+{
+    inner: IntervalMap<K, V>,
+}
+
+impl<K, V> InclusiveIntervalMap<K, V>
+where
+    K: Ord + Eq + Hash + Clone + Successor,
+    V: Eq + Clone,
+{
+    pub fn new(default: V) -> Self {
+        Self { inner: IntervalMap::new(default) }
+    }
+
+    /// Assign `value` to every key in the inclusive range `[key_begin, key_end]`.
+    ///
+    /// Unlike `IntervalMap::assign`, `key_begin == key_end` assigns a single
+    /// point, and `key_begin > key_end` is rejected. If an existing
+    /// same-valued run ends just before `key_begin` or begins at
+    /// `key_end.successor()`, that run is absorbed on either side so touching
+    /// ranges coalesce into one canonical segment regardless of insertion
+    /// order. After that, it returns whatever `IntervalMap::assign` returns
+    /// for the widened range, so it also returns `false` without assigning
+    /// when `value` already holds just before the range or at its end (e.g.
+    /// a range lying inside a run that already holds `value`).
+    ///
+    /// Returns `false` without assigning if `key_end` is the maximum
+    /// representable value of `K`: the underlying half-open map represents
+    /// `key_end` as `key_end.successor()`, and there's no such value to
+    /// represent "through the end of `K`'s range" with.
+    pub fn assign_inclusive(&mut self, key_begin: &K, key_end: &K, value: &V) -> bool {
+        if key_begin > key_end {
+            return false;
+        }
+        let Some(mut end) = key_end.successor() else {
+            return false;
+        };
+
+        // Absorb a touching run immediately to the left, so the underlying
+        // half-open assign sees one contiguous range rather than rejecting
+        // it as a same-value no-op at the shared boundary.
+        let mut begin = key_begin.clone();
+        if self.inner.value_map.contains_key(&begin) {
+            if let Some(prior_start) = self.inner.previous_key(&begin) {
+                if self.inner.value_map.get(prior_start) == Some(value) {
+                    begin = prior_start.clone();
+                }
+            }
+        }
+
+        // Mirror that on the right: a same-valued run starting exactly at
+        // `end` should be absorbed too, so ranges coalesce regardless of
+        // which one was assigned first.
+        if self.inner.value_map.get(&end) == Some(value) {
+            if let Some(run_end) = self.inner.next_key(&end) {
+                end = run_end.clone();
+            }
+        }
+
+        self.inner.assign(&begin, &end, value)
+    }
+}
+
+impl<K, V> Index<&K> for InclusiveIntervalMap<K, V>
+where
+    K: Ord + Eq + Hash + Clone + Successor,
+    V: Eq + Clone,
+{
+    type Output = V;
+
+    fn index(&self, key: &K) -> &Self::Output {
+        &self.inner[key]
+    }
+}
+
+impl<K, V> Debug for InclusiveIntervalMap<K, V>
+where
+    K: Ord + Eq + Hash + Debug + Clone + Successor,
+    V: Eq + Debug + Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
 }
\ No newline at end of file
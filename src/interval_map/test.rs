@@ -1,6 +1,6 @@
 #[cfg(test)]
 
-use super::{IntervalMap, BTreeMap};
+use super::{IntervalMap, InclusiveIntervalMap, BTreeMap};
 
 #[test]
 fn test_empty_indexing() {
@@ -114,3 +114,217 @@ fn test_next() {
     assert!(!map.assign(&0, &7, &'b'));
     assert!(map.assign(&0, &7, &'c'));
 }
+
+#[test]
+fn test_iter() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&0, &6, &'b');
+    map.assign(&10, &21, &'c');
+    let collected: Vec<_> = map.iter().map(|(range, value)| (range, *value)).collect();
+    assert_eq!(collected, vec![(0..6, 'b'), (10..21, 'c')]);
+
+    for (range, value) in &map {
+        assert!((range == (0..6) && *value == 'b') || (range == (10..21) && *value == 'c'));
+    }
+}
+
+#[test]
+fn test_iter_with_defaults() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&0, &6, &'b');
+    map.assign(&10, &21, &'c');
+    let collected: Vec<_> = map.iter_with_defaults().map(|(range, value)| (range, *value)).collect();
+    assert_eq!(collected, vec![(0..6, 'b'), (6..10, 'a'), (10..21, 'c')]);
+}
+
+#[test]
+fn test_range_queries_large_map() {
+    let mut map: IntervalMap<i32, i32> = IntervalMap::new(-1);
+    map.value_map = (0..2000).step_by(2).map(|k| (k, k)).collect::<BTreeMap<i32, i32>>();
+
+    for key in (-5..2005).step_by(7) {
+        let expected_next = map.value_map.keys().filter(|&&k| k > key).min().copied();
+        let expected_prev = map.value_map.keys().filter(|&&k| k < key).max().copied();
+        assert_eq!(map.next_key(&key).copied(), expected_next);
+        assert_eq!(map.previous_key(&key).copied(), expected_prev);
+    }
+
+    let expected_in_range = map.value_map.keys()
+        .filter(|&&key| key >= 500 && key < 1500)
+        .copied().collect::<Vec<i32>>();
+    assert_eq!(map.keys_in_range(&500, &1500), expected_in_range);
+}
+
+#[test]
+fn test_overlaps() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&5, &10, &'b');
+    assert!(map.overlaps(&0, &6)); // touches the start of the interval
+    assert!(map.overlaps(&9, &20)); // touches the end of the interval
+    assert!(!map.overlaps(&10, &20)); // starts exactly at the end (exclusive)
+    assert!(!map.overlaps(&0, &5)); // ends exactly at the start (exclusive)
+    assert!(!map.overlaps(&20, &30)); // default-only region
+}
+
+#[test]
+fn test_covering_interval() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&5, &10, &'b');
+    assert_eq!(map.covering_interval(&4), None);
+    assert_eq!(map.covering_interval(&5), Some((5, 10, &'b')));
+    assert_eq!(map.covering_interval(&9), Some((5, 10, &'b')));
+    assert_eq!(map.covering_interval(&10), None);
+}
+
+#[test]
+fn test_intervals_in() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&0, &6, &'b');
+    map.assign(&10, &21, &'c');
+    let collected: Vec<_> = map.intervals_in(&3, &15).map(|(range, value)| (range, *value)).collect();
+    assert_eq!(collected, vec![(3..6, 'b'), (10..15, 'c')]);
+    assert_eq!(map.intervals_in(&6, &10).count(), 0); // purely default-valued window
+}
+
+#[test]
+fn test_merge_with() {
+    let mut a: IntervalMap<i32, bool> = IntervalMap::new(false);
+    a.assign(&0, &10, &true);
+    let mut b: IntervalMap<i32, bool> = IntervalMap::new(false);
+    b.assign(&5, &15, &true);
+
+    a.merge_with(&b, |x, y| *x || *y);
+    let collected: Vec<_> = a.iter().map(|(range, value)| (range, *value)).collect();
+    assert_eq!(collected, vec![(0..15, true)]);
+}
+
+#[test]
+fn test_merge_with_keeps_canonical() {
+    let mut a: IntervalMap<i32, char> = IntervalMap::new('a');
+    a.assign(&0, &5, &'b');
+    a.assign(&10, &15, &'b');
+    let mut b: IntervalMap<i32, char> = IntervalMap::new('a');
+    b.assign(&5, &10, &'b');
+
+    a.merge_with(&b, |x, y| if *x != 'a' { *x } else { *y });
+    let collected: Vec<_> = a.iter().map(|(range, value)| (range, *value)).collect();
+    assert_eq!(collected, vec![(0..15, 'b')]); // merged and re-canonicalized into one run
+}
+
+#[test]
+fn test_gaps() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&0, &5, &'b');
+    map.assign(&10, &15, &'b');
+    let collected: Vec<_> = map.gaps(&0, &20).collect();
+    assert_eq!(collected, vec![5..10, 15..20]);
+}
+
+#[test]
+fn test_gaps_fully_assigned_window() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&0, &20, &'b');
+    assert_eq!(map.gaps(&5, &15).count(), 0);
+}
+
+#[test]
+fn test_gaps_inverted_window() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&0, &5, &'b');
+    assert_eq!(map.gaps(&10, &5).count(), 0); // nonsensical window, no panic
+    assert_eq!(map.gaps(&5, &5).count(), 0); // empty window
+}
+
+#[test]
+fn test_gaps_fully_default_window() {
+    let map: IntervalMap<i32, char> = IntervalMap::new('a');
+    let collected: Vec<_> = map.gaps(&0, &10).collect();
+    assert_eq!(collected, vec![0..10]);
+}
+
+#[test]
+fn test_inclusive_single_point() {
+    let mut map: InclusiveIntervalMap<i32, char> = InclusiveIntervalMap::new('a');
+    assert!(map.assign_inclusive(&5, &5, &'b'));
+    assert_eq!(map[&4], 'a');
+    assert_eq!(map[&5], 'b');
+    assert_eq!(map[&6], 'a');
+}
+
+#[test]
+fn test_inclusive_touching_ranges_coalesce() {
+    let mut map: InclusiveIntervalMap<i32, char> = InclusiveIntervalMap::new('a');
+    assert!(map.assign_inclusive(&0, &5, &'b'));
+    assert!(map.assign_inclusive(&6, &10, &'b'));
+    for key in 0..=10 {
+        assert_eq!(map[&key], 'b');
+    }
+    assert_eq!(map.inner.value_map.keys().count(), 2); // collapsed into one segment
+    assert_eq!(map[&11], 'a');
+}
+
+#[test]
+fn test_inclusive_touching_ranges_coalesce_reverse_order() {
+    let mut map: InclusiveIntervalMap<i32, char> = InclusiveIntervalMap::new('a');
+    assert!(map.assign_inclusive(&6, &10, &'b'));
+    assert!(map.assign_inclusive(&0, &5, &'b'));
+    for key in 0..=10 {
+        assert_eq!(map[&key], 'b');
+    }
+    assert_eq!(map.inner.value_map.keys().count(), 2); // collapsed into one segment
+    assert_eq!(map[&11], 'a');
+}
+
+#[test]
+fn test_inclusive_touching_ranges_different_values() {
+    let mut map: InclusiveIntervalMap<i32, char> = InclusiveIntervalMap::new('a');
+    assert!(map.assign_inclusive(&0, &5, &'b'));
+    assert!(map.assign_inclusive(&6, &10, &'c'));
+    assert_eq!(map[&5], 'b');
+    assert_eq!(map[&6], 'c');
+    assert_eq!(map[&11], 'a');
+}
+
+#[test]
+fn test_inclusive_rejects_backwards_range() {
+    let mut map: InclusiveIntervalMap<i32, char> = InclusiveIntervalMap::new('a');
+    assert!(!map.assign_inclusive(&5, &4, &'b'));
+}
+
+/// Test that a range ending at K::MAX is rejected rather than panicking or
+/// silently wrapping, since there's no successor to represent it with.
+#[test]
+fn test_inclusive_rejects_max_endpoint() {
+    let mut map: InclusiveIntervalMap<i32, char> = InclusiveIntervalMap::new('a');
+    assert!(!map.assign_inclusive(&(i32::MAX - 1), &i32::MAX, &'b'));
+    assert!(map.assign_inclusive(&(i32::MAX - 2), &(i32::MAX - 1), &'b'));
+    assert_eq!(map[&(i32::MAX - 1)], 'b');
+    assert_eq!(map[&i32::MAX], 'a');
+}
+
+#[test]
+fn test_get_borrowed_str_key() {
+    let mut map: IntervalMap<String, i32> = IntervalMap::new(0);
+    map.assign(&"b".to_string(), &"d".to_string(), &1);
+    assert_eq!(*map.get("a"), 0);
+    assert_eq!(*map.get("b"), 1);
+    assert_eq!(*map.get("c"), 1);
+    assert_eq!(*map.get("d"), 0);
+}
+
+#[test]
+fn test_get_exact() {
+    let mut map: IntervalMap<i32, char> = IntervalMap::new('a');
+    map.assign(&2, &5, &'b');
+    assert_eq!(map.get_exact(&2), Some(&'b')); // start of stored interval
+    assert_eq!(map.get_exact(&5), Some(&'a')); // the canonical end marker is also a stored key
+    assert_eq!(map.get_exact(&3), None); // interior point, not a boundary
+    assert_eq!(map.get_exact(&0), None); // before any stored key
+}
+
+#[test]
+fn test_iter_empty() {
+    let map: IntervalMap<i32, char> = IntervalMap::new('a');
+    assert_eq!(map.iter().count(), 0);
+    assert_eq!(map.iter_with_defaults().count(), 0);
+}